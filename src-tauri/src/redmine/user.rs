@@ -1,14 +1,17 @@
 //! Manage a **Redmine user**
 
-use chrono::NaiveDateTime;
-use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use chrono::{DateTime, Utc};
+use serde::de::IntoDeserializer;
+use serde::{de, Deserialize, Serialize};
+use serde_repr::Serialize_repr;
 
 use crate::utils::datetime;
+use crate::utils::serde_aux::deserialize_u32_flexible;
 
 /// `User` is a type that represents a **Redmine** user.
 #[derive(Serialize, Deserialize)]
 pub struct User {
+  #[serde(deserialize_with = "deserialize_u32_flexible")]
   pub id: u32,
   pub firstname: String,
   pub lastname: String,
@@ -17,17 +20,17 @@ pub struct User {
   pub admin: bool,
   pub status: Option<UserStatus>,
 
-  #[serde(with = "datetime::serde_iso_8601")]
-  pub last_login_on: NaiveDateTime,
+  #[serde(default, with = "datetime::serde_iso_8601::utc::option")]
+  pub last_login_on: Option<DateTime<Utc>>,
 
-  #[serde(with = "datetime::serde_iso_8601")]
-  pub passwd_changed_on: NaiveDateTime,
+  #[serde(with = "datetime::serde_iso_8601::utc")]
+  pub passwd_changed_on: DateTime<Utc>,
 
-  #[serde(with = "datetime::serde_iso_8601")]
-  pub created_on: NaiveDateTime,
+  #[serde(with = "datetime::serde_iso_8601::utc")]
+  pub created_on: DateTime<Utc>,
 
-  #[serde(with = "datetime::serde_iso_8601")]
-  pub updated_on: NaiveDateTime,
+  #[serde(with = "datetime::serde_iso_8601::utc")]
+  pub updated_on: DateTime<Utc>,
 }
 
 impl User {
@@ -36,8 +39,9 @@ impl User {
   /// The **Redmine API** does not return the `status` field when performing a bulk
   /// request to the **/users** endpoint. Because of this, the `status` property is
   /// optional in `User` and will be set to `None` if missing from the JSON input.
-  /// Other fields are always returned and are therefore mandatory in the JSON
-  /// input.
+  /// Likewise, `last_login_on` is omitted or `null` for a user that has never
+  /// logged in, so it is also optional. Other fields are always returned and are
+  /// therefore mandatory in the JSON input.
   ///
   /// # Example
   ///
@@ -70,7 +74,8 @@ impl User {
   ///   chrono::NaiveDate::from_ymd_opt(2015, 10, 30)
   ///     .unwrap()
   ///     .and_hms_opt(12, 9, 31)
-  ///     .unwrap(),
+  ///     .unwrap()
+  ///     .and_utc(),
   /// );
   /// ```
   ///
@@ -106,22 +111,28 @@ impl User {
   ///   login: String::from("email@henskelis.fr"),
   ///   admin: true,
   ///   status: None,
-  ///   last_login_on: NaiveDate::from_ymd_opt(2023, 7, 20)
-  ///     .unwrap()
-  ///     .and_hms_opt(16, 23, 14)
-  ///     .unwrap(),
+  ///   last_login_on: Some(
+  ///     NaiveDate::from_ymd_opt(2023, 7, 20)
+  ///       .unwrap()
+  ///       .and_hms_opt(16, 23, 14)
+  ///       .unwrap()
+  ///       .and_utc(),
+  ///   ),
   ///   passwd_changed_on: NaiveDate::from_ymd_opt(2015, 11, 5)
   ///     .unwrap()
   ///     .and_hms_opt(17, 25, 2)
-  ///     .unwrap(),
+  ///     .unwrap()
+  ///     .and_utc(),
   ///   created_on: NaiveDate::from_ymd_opt(2015, 10, 30)
   ///     .unwrap()
   ///     .and_hms_opt(12, 9, 31)
-  ///     .unwrap(),
+  ///     .unwrap()
+  ///     .and_utc(),
   ///   updated_on: NaiveDate::from_ymd_opt(2015, 11, 5)
   ///     .unwrap()
   ///     .and_hms_opt(17, 25, 2)
-  ///     .unwrap(),
+  ///     .unwrap()
+  ///     .and_utc(),
   /// };
   ///
   /// let expected_json = r#"
@@ -150,10 +161,173 @@ impl User {
   pub fn to_json(&self) -> String {
     serde_json::to_string(self).unwrap()
   }
+
+  /// Build a `Vec<User>` from a CSV string.
+  ///
+  /// CSV has no native representation for `null`, so the `status` column and
+  /// any optional timestamp column (currently `last_login_on`) are read as
+  /// string cells: an empty cell deserializes to `None`. A non-empty timestamp
+  /// cell is parsed the same way the JSON form would accept it; a non-empty
+  /// `status` cell must hold the status name (see [`UserStatus::as_str`],
+  /// matched case-insensitively), numeric status codes are not accepted here.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use redmium::redmine::user::User;
+  ///
+  /// let csv = "\
+  /// id,firstname,lastname,mail,login,admin,status,last_login_on,passwd_changed_on,created_on,updated_on
+  /// 1,Hen,SKELIS,email@henskelis.fr,email@henskelis.fr,true,active,,2015-10-30T12:09:31Z,2015-10-30T12:09:31Z,2021-11-15T11:42:22Z
+  /// ";
+  ///
+  /// let users = User::build_many_from_csv(csv).unwrap();
+  ///
+  /// assert_eq!(users.len(), 1);
+  /// assert_eq!(users[0].login, "email@henskelis.fr");
+  /// assert!(users[0].last_login_on.is_none());
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// The conversion can fail if the CSV input is malformed, is missing one of
+  /// the expected columns, or contains a cell that cannot be parsed into the
+  /// type expected by `User` (e.g. an invalid `status` name or a timestamp
+  /// that isn't in the ISO 8601 format).
+  pub fn build_many_from_csv(csv: &str) -> Result<Vec<Self>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new().from_reader(csv.as_bytes());
+
+    reader
+      .deserialize::<UserCsvRecord>()
+      .map(|record| {
+        record.and_then(|record| {
+          User::try_from(record).map_err(|error| csv::Error::from(std::io::Error::other(error)))
+        })
+      })
+      .collect()
+  }
+}
+
+/// Build the CSV representation of a collection of `User`s, using the same
+/// field names as the JSON form for the header row.
+///
+/// # Panics
+///
+/// Panics if any `User` fails to serialize, which should not happen given
+/// `User`'s `Serialize` implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use redmium::redmine::user::{users_to_csv, User};
+///
+/// let user = User {
+///   id: 1,
+///   firstname: String::from("Hen"),
+///   lastname: String::from("SKELIS"),
+///   mail: String::from("email@henskelis.fr"),
+///   login: String::from("email@henskelis.fr"),
+///   admin: true,
+///   status: None,
+///   last_login_on: None,
+///   passwd_changed_on: Utc.with_ymd_and_hms(2015, 10, 30, 12, 9, 31).unwrap(),
+///   created_on: Utc.with_ymd_and_hms(2015, 10, 30, 12, 9, 31).unwrap(),
+///   updated_on: Utc.with_ymd_and_hms(2021, 11, 15, 11, 42, 22).unwrap(),
+/// };
+///
+/// let csv = users_to_csv(&[user]);
+///
+/// assert!(csv.starts_with("id,firstname,lastname,mail,login,admin,status,last_login_on,passwd_changed_on,created_on,updated_on\n"));
+/// assert!(csv.contains("1,Hen,SKELIS,email@henskelis.fr,email@henskelis.fr,true,,,2015-10-30T12:09:31Z"));
+/// ```
+pub fn users_to_csv(users: &[User]) -> String {
+  let mut writer = csv::WriterBuilder::new().from_writer(vec![]);
+
+  for user in users {
+    writer.serialize(UserCsvRecord::from(user)).unwrap();
+  }
+
+  String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+/// CSV-friendly mirror of `User`, routing the `status` and `last_login_on`
+/// optional fields through plain string cells since CSV has no native `null`.
+#[derive(Serialize, Deserialize)]
+struct UserCsvRecord {
+  id: u32,
+  firstname: String,
+  lastname: String,
+  mail: String,
+  login: String,
+  admin: bool,
+  status: String,
+  last_login_on: String,
+  passwd_changed_on: String,
+  created_on: String,
+  updated_on: String,
+}
+
+impl From<&User> for UserCsvRecord {
+  fn from(user: &User) -> Self {
+    UserCsvRecord {
+      id: user.id,
+      firstname: user.firstname.clone(),
+      lastname: user.lastname.clone(),
+      mail: user.mail.clone(),
+      login: user.login.clone(),
+      admin: user.admin,
+      status: user.status.as_ref().map_or(String::new(), |status| status.as_str().to_string()),
+      last_login_on: user
+        .last_login_on
+        .map_or(String::new(), |dt| dt.format(datetime::serde_iso_8601::DATETIME_FORMAT).to_string()),
+      passwd_changed_on: user
+        .passwd_changed_on
+        .format(datetime::serde_iso_8601::DATETIME_FORMAT)
+        .to_string(),
+      created_on: user.created_on.format(datetime::serde_iso_8601::DATETIME_FORMAT).to_string(),
+      updated_on: user.updated_on.format(datetime::serde_iso_8601::DATETIME_FORMAT).to_string(),
+    }
+  }
+}
+
+impl TryFrom<UserCsvRecord> for User {
+  type Error = String;
+
+  fn try_from(record: UserCsvRecord) -> Result<Self, Self::Error> {
+    // Reuse the same tolerant `serde` parser the JSON form goes through,
+    // by handing it the cell as a standalone `Deserializer`.
+    let parse_datetime = |s: &str| {
+      datetime::serde_iso_8601::utc::deserialize(s.into_deserializer())
+        .map_err(|error: de::value::Error| error.to_string())
+    };
+
+    Ok(User {
+      id: record.id,
+      firstname: record.firstname,
+      lastname: record.lastname,
+      mail: record.mail,
+      login: record.login,
+      admin: record.admin,
+      status: if record.status.is_empty() {
+        None
+      } else {
+        Some(record.status.parse()?)
+      },
+      last_login_on: if record.last_login_on.is_empty() {
+        None
+      } else {
+        Some(parse_datetime(&record.last_login_on)?)
+      },
+      passwd_changed_on: parse_datetime(&record.passwd_changed_on)?,
+      created_on: parse_datetime(&record.created_on)?,
+      updated_on: parse_datetime(&record.updated_on)?,
+    })
+  }
 }
 
 /// `UserStatus` represents a **Redmine** user account status.
-#[derive(Serialize_repr, Deserialize_repr)]
+#[derive(Serialize_repr)]
 #[repr(u8)]
 pub enum UserStatus {
   Anonymous,
@@ -161,3 +335,68 @@ pub enum UserStatus {
   Registered,
   Locked,
 }
+
+impl UserStatus {
+  /// Return the human-readable name of the `UserStatus`, as used by the
+  /// string form accepted by `Deserialize`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use redmium::redmine::user::UserStatus;
+  ///
+  /// assert_eq!(UserStatus::Active.as_str(), "active");
+  /// ```
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      UserStatus::Anonymous => "anonymous",
+      UserStatus::Active => "active",
+      UserStatus::Registered => "registered",
+      UserStatus::Locked => "locked",
+    }
+  }
+}
+
+/// `Deserialize` for `UserStatus` accepts either the numeric **Redmine**
+/// status code (`0..=3`) or its symbolic name (case-insensitive), since
+/// some tooling and exported fixtures carry the name instead of the code.
+impl<'de> Deserialize<'de> for UserStatus {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: de::Deserializer<'de>,
+  {
+    let value = serde_json::Value::deserialize(deserializer)?;
+
+    match value {
+      serde_json::Value::Number(number) => match number.as_u64() {
+        Some(0) => Ok(UserStatus::Anonymous),
+        Some(1) => Ok(UserStatus::Active),
+        Some(2) => Ok(UserStatus::Registered),
+        Some(3) => Ok(UserStatus::Locked),
+        _ => Err(de::Error::custom(format!(
+          "invalid UserStatus code: {number}"
+        ))),
+      },
+      serde_json::Value::String(name) => name.parse().map_err(de::Error::custom),
+      _ => Err(de::Error::custom(
+        "UserStatus must be either a number or a string",
+      )),
+    }
+  }
+}
+
+impl std::str::FromStr for UserStatus {
+  type Err = String;
+
+  /// Parse a `UserStatus` from its human-readable name, matched
+  /// case-insensitively (see [`UserStatus::as_str`]).
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    match name.to_lowercase().as_str() {
+      "anonymous" => Ok(UserStatus::Anonymous),
+      "active" => Ok(UserStatus::Active),
+      "registered" => Ok(UserStatus::Registered),
+      "locked" => Ok(UserStatus::Locked),
+      _ => Err(format!("invalid UserStatus name: {name}")),
+    }
+  }
+}
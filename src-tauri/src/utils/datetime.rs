@@ -3,7 +3,11 @@
 /// Handle the **Serialization** and the **Deserialization** of a
 /// **DateTime** to/from an **ISO 8601** formatted string with `serde`.
 pub mod serde_iso_8601 {
-  const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+  /// The canonical **ISO 8601** format that every datetime is serialized with.
+  ///
+  /// Exposed so other modules (such as CSV (de)serialization) can format or
+  /// parse a datetime the same way without duplicating the pattern.
+  pub const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
 
   use chrono::NaiveDateTime;
   use serde::{de, ser};
@@ -45,6 +49,11 @@ pub mod serde_iso_8601 {
 
   /// Deserialize a `NaiveDateTime` from an **ISO 6801** formatted string.
   ///
+  /// Not every **Redmine** instance or plugin emits the exact canonical
+  /// format, so a handful of commonly seen variants (fractional seconds,
+  /// a space instead of `T`, an offset instead of `Z`) are also accepted;
+  /// only the canonical format is ever produced on serialize.
+  ///
   /// Intended for use with `serde`s `deserialize_with` attribute.
   ///
   /// # Example:
@@ -79,6 +88,47 @@ pub mod serde_iso_8601 {
     deserializer.deserialize_str(NaiveDateTimeVisitor)
   }
 
+  /// Candidate formats with no UTC offset of their own, tried in order when
+  /// parsing a datetime, to tolerate the format drift observed across
+  /// **Redmine** instances and plugins. The canonical [`DATETIME_FORMAT`] is
+  /// always tried first, so it remains the preferred match, and is the only
+  /// format ever used to serialize.
+  const NAIVE_DATETIME_FORMATS: &[&str] =
+    &[DATETIME_FORMAT, "%Y-%m-%dT%H:%M:%S%.fZ", "%Y-%m-%d %H:%M:%S"];
+
+  /// A candidate format carrying an explicit UTC offset (e.g. `+05:00`)
+  /// instead of `Z`. Unlike [`NAIVE_DATETIME_FORMATS`], the offset must be
+  /// applied rather than discarded, or the parsed instant would silently
+  /// drift by however far off UTC it was.
+  const OFFSET_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%:z";
+
+  /// Parse a datetime string against the naive formats, then the
+  /// offset-aware one, returning the first that matches. Shared by every
+  /// `deserialize` in this module (and its `option`/`utc` siblings) so none
+  /// of them fall back to the strict canonical-only parsing the others
+  /// tolerate.
+  fn parse_flexible<E>(v: &str) -> Result<NaiveDateTime, E>
+  where
+    E: de::Error,
+  {
+    let naive = NAIVE_DATETIME_FORMATS
+      .iter()
+      .find_map(|format| NaiveDateTime::parse_from_str(v, format).ok());
+
+    let with_offset_applied = naive.or_else(|| {
+      chrono::DateTime::parse_from_str(v, OFFSET_DATETIME_FORMAT)
+        .ok()
+        .map(|dt| dt.with_timezone(&chrono::Utc).naive_utc())
+    });
+
+    with_offset_applied.ok_or_else(|| {
+      E::custom(format!(
+        "{v} does not match any of the supported datetime formats: {}, {OFFSET_DATETIME_FORMAT}",
+        NAIVE_DATETIME_FORMATS.join(", "),
+      ))
+    })
+  }
+
   struct NaiveDateTimeVisitor;
 
   impl<'de> de::Visitor<'de> for NaiveDateTimeVisitor {
@@ -92,9 +142,200 @@ pub mod serde_iso_8601 {
     where
       E: de::Error,
     {
-      match NaiveDateTime::parse_from_str(v, DATETIME_FORMAT) {
-        Ok(naive_datetime) => Ok(naive_datetime),
-        Err(parse_error) => Err(E::custom(parse_error)),
+      parse_flexible(v)
+    }
+  }
+
+  /// Handle the **Serialization** and the **Deserialization** of an
+  /// **optional DateTime** to/from an **ISO 8601** formatted string with `serde`.
+  ///
+  /// **Redmine** omits or returns `null` for some timestamps, such as
+  /// `last_login_on` when a user has never logged in, so this module allows
+  /// those fields to be represented as `Option<NaiveDateTime>` instead of
+  /// forcing every timestamp to be mandatory.
+  pub mod option {
+    use chrono::NaiveDateTime;
+    use serde::{de, ser, Deserialize};
+
+    use super::DATETIME_FORMAT;
+
+    /// Serialize an optional datetime into an **ISO 8601** formatted string.
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use serde::Serialize;
+    /// use redmium::utils::datetime::serde_iso_8601;
+    ///
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///   #[serde(serialize_with = "serde_iso_8601::option::serialize")]
+    ///   datetime: Option<NaiveDateTime>,
+    /// }
+    ///
+    /// let my_s = S { datetime: None };
+    ///
+    /// let serialized = serde_json::to_string(&my_s).unwrap();
+    ///
+    /// assert_eq!(r#"{"datetime":null}"#, serialized);
+    /// ```
+    pub fn serialize<S>(dt: &Option<NaiveDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: ser::Serializer,
+    {
+      match dt {
+        Some(dt) => serializer.serialize_str(dt.format(DATETIME_FORMAT).to_string().as_str()),
+        None => serializer.serialize_none(),
+      }
+    }
+
+    /// Deserialize an `Option<NaiveDateTime>` from an **ISO 8601** formatted
+    /// string, treating a `null` value as `None`. A field that is absent from
+    /// the input is only read as `None` when it also carries `#[serde(default)]`.
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use redmium::utils::datetime::serde_iso_8601;
+    ///
+    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// struct S {
+    ///   #[serde(deserialize_with = "serde_iso_8601::option::deserialize")]
+    ///   datetime: Option<chrono::NaiveDateTime>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"datetime":null}"#).unwrap();
+    ///
+    /// assert_eq!(S { datetime: None }, my_s);
+    /// ```
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDateTime>, D::Error>
+    where
+      D: de::Deserializer<'de>,
+    {
+      let opt: Option<String> = Option::deserialize(deserializer)?;
+
+      opt.map(|s| super::parse_flexible(&s)).transpose()
+    }
+  }
+
+  /// Handle the **Serialization** and the **Deserialization** of a
+  /// timezone-aware **DateTime** to/from an **ISO 8601** formatted string
+  /// with `serde`.
+  ///
+  /// Unlike the parent module, this keeps the `Z` suffix meaningful by
+  /// carrying the UTC timezone all the way through, so a `DateTime<Utc>`
+  /// round-trips as a true instant instead of being collapsed into a
+  /// `NaiveDateTime`.
+  pub mod utc {
+    use chrono::{DateTime, Utc};
+    use serde::{de, ser};
+
+    use super::DATETIME_FORMAT;
+
+    /// Serialize a `DateTime<Utc>` into an **ISO 8601** formatted string.
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use serde::Serialize;
+    /// use redmium::utils::datetime::serde_iso_8601;
+    ///
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///   #[serde(serialize_with = "serde_iso_8601::utc::serialize")]
+    ///   datetime: chrono::DateTime<Utc>,
+    /// }
+    ///
+    /// let my_s = S {
+    ///   datetime: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap(),
+    /// };
+    ///
+    /// let serialized = serde_json::to_string(&my_s).unwrap();
+    ///
+    /// assert_eq!(r#"{"datetime":"2015-05-15T10:00:00Z"}"#, serialized);
+    /// ```
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: ser::Serializer,
+    {
+      serializer.serialize_str(dt.format(DATETIME_FORMAT).to_string().as_str())
+    }
+
+    /// Deserialize a `DateTime<Utc>` from an **ISO 8601** formatted string.
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use serde::Deserialize;
+    /// use redmium::utils::datetime::serde_iso_8601;
+    ///
+    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// struct S {
+    ///   #[serde(deserialize_with = "serde_iso_8601::utc::deserialize")]
+    ///   datetime: chrono::DateTime<Utc>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"datetime":"2015-05-15T10:00:00Z"}"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///   S {
+    ///     datetime: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap(),
+    ///   },
+    ///   my_s,
+    /// );
+    /// ```
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+      D: de::Deserializer<'de>,
+    {
+      super::deserialize(deserializer).map(|naive_datetime| naive_datetime.and_utc())
+    }
+
+    /// Handle the **Serialization** and the **Deserialization** of an
+    /// **optional** timezone-aware **DateTime** to/from an **ISO 8601**
+    /// formatted string with `serde`.
+    pub mod option {
+      use chrono::{DateTime, Utc};
+      use serde::{de, ser};
+
+      use super::DATETIME_FORMAT;
+
+      /// Serialize an optional `DateTime<Utc>` into an **ISO 8601** formatted string.
+      ///
+      /// Intended for use with `serde`s `serialize_with` attribute.
+      pub fn serialize<S>(dt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: ser::Serializer,
+      {
+        match dt {
+          Some(dt) => serializer.serialize_str(dt.format(DATETIME_FORMAT).to_string().as_str()),
+          None => serializer.serialize_none(),
+        }
+      }
+
+      /// Deserialize an `Option<DateTime<Utc>>` from an **ISO 8601** formatted
+      /// string, treating a `null` value as `None`. A field that is absent from
+      /// the input is only read as `None` when it also carries `#[serde(default)]`.
+      ///
+      /// Intended for use with `serde`s `deserialize_with` attribute.
+      pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+      where
+        D: de::Deserializer<'de>,
+      {
+        super::super::option::deserialize(deserializer)
+          .map(|opt| opt.map(|naive_datetime| naive_datetime.and_utc()))
       }
     }
   }
@@ -0,0 +1,47 @@
+//! Auxiliary `serde` helpers for fields that may arrive in more than one shape
+
+use serde::{de, Deserialize};
+
+/// Deserialize a `u32` from either a JSON number or a string containing one.
+///
+/// Some **Redmine** proxies and CSV-origin payloads deliver numeric
+/// identifiers as quoted strings (e.g. `"id": "42"`), which fails to
+/// deserialize directly into a `u32`. This accepts either shape, while
+/// serialization is left untouched so output stays a bare number.
+///
+/// Intended for use with `serde`s `deserialize_with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use redmium::utils::serde_aux::deserialize_u32_flexible;
+///
+/// #[derive(Deserialize, PartialEq, Debug)]
+/// struct S {
+///   #[serde(deserialize_with = "deserialize_u32_flexible")]
+///   id: u32,
+/// }
+///
+/// let from_number: S = serde_json::from_str(r#"{"id":42}"#).unwrap();
+/// let from_string: S = serde_json::from_str(r#"{"id":"42"}"#).unwrap();
+///
+/// assert_eq!(S { id: 42 }, from_number);
+/// assert_eq!(S { id: 42 }, from_string);
+/// ```
+pub fn deserialize_u32_flexible<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+  D: de::Deserializer<'de>,
+{
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum U32OrString {
+    U32(u32),
+    String(String),
+  }
+
+  match U32OrString::deserialize(deserializer)? {
+    U32OrString::U32(id) => Ok(id),
+    U32OrString::String(id) => id.parse().map_err(de::Error::custom),
+  }
+}